@@ -46,6 +46,7 @@ fn default_repeat_penalty() -> f64 {
     1.0
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     #[serde(rename = "api_url", default = "default_api_url")]
@@ -8,7 +8,12 @@ use crate::commands::{
 };
 use crate::infrastructure::{
     lm::client::{Client, ModelSettings},
-    storage::history_store::{HistoryStore, Message, Role},
+    storage::history_store::{HistoryStore, Role},
+};
+use crate::tools::{
+    call::{ToolCall, ToolCallAccumulator},
+    handlers::{edit_file::EditFileTool, read_file::ReadFileTool, run_shell::RunShellTool},
+    registry::ToolRegistry,
 };
 use std::io::{self, Write};
 
@@ -17,8 +22,11 @@ use dialoguer::{console::Style, theme::ColorfulTheme};
 use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
-use reqwest_eventsource::Event;
-use tracing::{debug, error, info, instrument, warn};
+use reqwest_eventsource::{Error as EventSourceError, Event};
+use tracing::{debug, warn};
+
+// ツール呼び出しが延々と続くのを防ぐための上限
+const MAX_TOOL_ROUNDS: usize = 32;
 
 pub struct OpenCoder {
     pub client: Client,
@@ -26,9 +34,16 @@ pub struct OpenCoder {
     prompt: Prompt,
     pub model: ModelSettings,
     history_store: HistoryStore,
+    tools: ToolRegistry,
     pub theme: ColorfulTheme
 }
 
+/// 1回のリクエストで得られたアシスタントの応答
+struct AssistantTurn {
+    content: String,
+    tool_calls: Vec<ToolCall>,
+}
+
 impl OpenCoder {
     pub fn new(config: Config) -> Result<Self> {
         let client = Client::new(config.clone()).context("Failed to initialize LM client")?;
@@ -51,12 +66,19 @@ impl OpenCoder {
             ..ColorfulTheme::default()
         };
 
+        let tools = ToolRegistry::new(vec![
+            Box::new(ReadFileTool::new()),
+            Box::new(EditFileTool::new()?),
+            Box::new(RunShellTool::new()?),
+        ]);
+
         let app = Self {
             client,
             output: OutputHandler::new()?,
             prompt: Prompt::new()?,
             model,
             history_store: HistoryStore::new("You are a helpful assistant.")?,
+            tools,
             theme
         };
 
@@ -68,7 +90,7 @@ impl OpenCoder {
         self.output.show_welcome_message();
 
         let mut registry = CommandRegistry::new()?;
-        let commands = vec![
+        let commands = [
             Command {
                 name: "/exit".to_string(),
                 description: "Exit from application.".to_string(),
@@ -131,6 +153,48 @@ impl OpenCoder {
     }
 
     async fn handle_chat(&mut self, input: &str) -> Result<()> {
+        // historyにユーザーの入力を追加
+        self.history_store.add_history(Role::User, input)?;
+
+        // モデルがツールを呼ばなくなるまでリクエストを繰り返す
+        for _ in 0..MAX_TOOL_ROUNDS {
+            let turn = self.stream_assistant_turn().await?;
+
+            if turn.tool_calls.is_empty() {
+                if !turn.content.is_empty() {
+                    self.history_store.add_history(Role::Assistant, &turn.content)?;
+                }
+                return Ok(());
+            }
+
+            self.history_store.add_tool_calls(&turn.content, &turn.tool_calls)?;
+
+            for call in &turn.tool_calls {
+                self.output.print_tool_call(call)?;
+                let result = self.tools.execute(call).await;
+                self.output.print_tool_result(call, &result)?;
+
+                // 失敗した場合もエラー内容をモデルに返して自己修正させる
+                let content = match result {
+                    Ok(output) => output,
+                    Err(err) => {
+                        warn!("Tool {} failed: {:#}", call.name, err);
+                        format!("Error: {:#}", err)
+                    }
+                };
+                self.history_store.add_tool_result(&call.id, &content)?;
+            }
+        }
+
+        self.output.print_warning(&format!(
+            "Stopped after {} tool rounds without a final answer.",
+            MAX_TOOL_ROUNDS
+        ))?;
+
+        Ok(())
+    }
+
+    async fn stream_assistant_turn(&mut self) -> Result<AssistantTurn> {
         // 処理中であることを示すスピナーを表示する
         println!();
         let spinner = ProgressBar::new_spinner();
@@ -141,17 +205,16 @@ impl OpenCoder {
         );
         spinner.enable_steady_tick(std::time::Duration::from_millis(120));
 
-        // historyにユーザーの入力を追加
-        self.history_store.add_history(Role::User, input)?;
         let messages = self.history_store.history();
 
         // 完全なレスポンスを保存するための変数
         let mut full_response = String::new();
+        let mut tool_calls = ToolCallAccumulator::new();
 
         // ストリーミング処理
         let mut es = self
             .client
-            .stream_chat_completions(self.model.clone(), messages)
+            .stream_chat_completions(self.model.clone(), messages, self.tools.definitions())
             .await?;
         while let Some(event) = es.next().await {
             match event {
@@ -167,18 +230,26 @@ impl OpenCoder {
                     }
 
                     let formatted_message = self.output.format_model_stream_response(message.data).await?;
-                    match formatted_message.0 {
-                        true => {
-                            es.close();
-                            println!("\n");
-                        }
-                        false => {
-                            full_response.push_str(&formatted_message.1);
-
-                            print!("{}", formatted_message.1);
-                            io::stdout().flush()?;
-                        }
+
+                    if !formatted_message.content.is_empty() {
+                        full_response.push_str(&formatted_message.content);
+
+                        print!("{}", formatted_message.content);
+                        io::stdout().flush()?;
                     }
+
+                    for delta in formatted_message.tool_calls {
+                        tool_calls.push(delta);
+                    }
+
+                    if let Some(finish_reason) = formatted_message.finish_reason {
+                        debug!("Stream finished: {}", finish_reason);
+                        es.close();
+                        println!("\n");
+                    }
+                }
+                Err(EventSourceError::StreamEnded) => {
+                    es.close();
                 }
                 Err(err) => {
                     if !spinner.is_finished() {
@@ -191,11 +262,9 @@ impl OpenCoder {
             }
         }
 
-        if !full_response.is_empty() {
-            self.history_store
-                .add_history(Role::Assistant, &full_response)?;
-        }
-
-        Ok(())
+        Ok(AssistantTurn {
+            content: full_response,
+            tool_calls: tool_calls.finish(),
+        })
     }
 }
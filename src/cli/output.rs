@@ -1,14 +1,24 @@
+use crate::tools::call::{ToolCall, ToolCallDelta};
+
 use anyhow::{Context, Result};
 use cfonts::{say, Align, BgColors, Colors, Env, Fonts, Options};
 use dialoguer::console::Term;
 use owo_colors::OwoColorize;
 use reqwest::Response;
-use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
+use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
 
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct Message { pub model: String, pub message: String, }
 
+#[derive(Debug, Default)]
+pub struct StreamResponse {
+    pub content: String,
+    pub tool_calls: Vec<ToolCallDelta>,
+    pub finish_reason: Option<String>,
+}
+
 pub struct OutputHandler {}
 
 impl OutputHandler {
@@ -16,6 +26,7 @@ impl OutputHandler {
         Ok(Self{})
     }
 
+    #[allow(dead_code)]
     pub async fn format_model_response(&self, input: Response) -> Result<Message> {
         let response_text = input.text().await?;
         let response_json: Value = serde_json::from_str(&response_text)
@@ -34,22 +45,31 @@ impl OutputHandler {
         Ok(Message{ model: output_model, message: output_message })
     }
 
-    pub async fn format_model_stream_response(&self, input: String) -> Result<(bool, String)> {
-        let response_text = input.as_str();
-        let response_json: Value = serde_json::from_str(&response_text)
+    pub async fn format_model_stream_response(&self, input: String) -> Result<StreamResponse> {
+        // ストリームの終端
+        if input.trim() == "[DONE]" {
+            return Ok(StreamResponse { finish_reason: Some("stop".to_string()), ..Default::default() });
+        }
+
+        let response_json: Value = serde_json::from_str(&input)
             .context("Failed to parse response JSON")?;
+        let choice = &response_json["choices"][0];
 
-        let output_message = response_json["choices"][0]["delta"]["content"]
+        let content = choice["delta"]["content"]
             .as_str()
             .unwrap_or("")
             .to_string();
 
-        let is_finish = response_json["choices"][0]["finish_reason"]
+        let tool_calls = choice["delta"]["tool_calls"]
+            .as_array()
+            .map(|calls| calls.iter().filter_map(ToolCallDelta::from_json).collect())
+            .unwrap_or_default();
+
+        let finish_reason = choice["finish_reason"]
             .as_str()
-            .map(|s| s == "stop".to_string())
-            .unwrap_or(false);
+            .map(|s| s.to_string());
 
-        Ok((is_finish, output_message))
+        Ok(StreamResponse { content, tool_calls, finish_reason })
     }
 
     pub fn print_command_response(&self, input: &str) -> Result<()> {
@@ -65,6 +85,58 @@ impl OutputHandler {
         Ok(())
     }
 
+    pub fn print_tool_call(&self, call: &ToolCall) -> Result<()> {
+        println!("{} {}({})", "⚙".cyan(), call.name.bold(), call.arguments.bright_black());
+
+        Ok(())
+    }
+
+    pub fn print_tool_result(&self, call: &ToolCall, result: &Result<String>) -> Result<()> {
+        match result {
+            Ok(output) => println!(
+                "  {} {} ({} lines)\n",
+                "✓".green(),
+                call.name,
+                output.lines().count()
+            ),
+            Err(err) => println!("  {} {}: {}\n", "✗".red(), call.name, err),
+        }
+
+        Ok(())
+    }
+
+    pub fn print_diff(&self, path: &str, old: &str, new: &str) -> Result<()> {
+        let diff = TextDiff::from_lines(old, new);
+
+        println!("{}", format!("--- {}", path).bold());
+        println!("{}", format!("+++ {}", path).bold());
+        for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+            println!("{}", hunk.header().cyan());
+            for change in hunk.iter_changes() {
+                let line = change.to_string_lossy();
+                let line = line.trim_end_matches('\n');
+                match change.tag() {
+                    ChangeTag::Delete => println!("{}", format!("-{}", line).red()),
+                    ChangeTag::Insert => println!("{}", format!("+{}", line).green()),
+                    ChangeTag::Equal => println!(" {}", line),
+                }
+            }
+        }
+        println!();
+
+        Ok(())
+    }
+
+    pub fn print_shell_line(&self, line: &str, is_stderr: bool) -> Result<()> {
+        if is_stderr {
+            println!("  {} {}", "│".bright_black(), line.red());
+        } else {
+            println!("  {} {}", "│".bright_black(), line.bright_black());
+        }
+
+        Ok(())
+    }
+
     // ToDo: 独自の出力方法にする
     pub fn print_error(&self, input: &str) -> Result<()> {
         println!("\n{}\n", input);
@@ -1,6 +1,7 @@
 #[derive(Clone, Debug)]
 pub struct Command {
     pub name: String,
+    #[allow(dead_code)]
     pub description: String,
 }
 
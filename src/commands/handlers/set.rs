@@ -22,7 +22,7 @@ pub async fn set(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
     }
 }
 
-async fn set_model(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+async fn set_model(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     let models_json = open_coder.client.get_model_list().await?;
     let models_list = models_json["data"].as_array().unwrap();
     let models_ids: Vec<String> = models_list.iter()
@@ -44,7 +44,7 @@ async fn set_model(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
     Ok(format!("Set model to {}", selected_model_name))
 }
 
-fn set_top_p(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_top_p(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: f64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("top_p(Current: {}):", open_coder.model.top_p))
@@ -56,7 +56,7 @@ fn set_top_p(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
     Ok(format!("Set top_p to {}", open_coder.model.top_p))
 }
 
-fn set_top_k(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_top_k(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: u64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("top_k(Current: {}):", open_coder.model.top_k))
@@ -68,7 +68,7 @@ fn set_top_k(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
     Ok(format!("Set top_k to {}", open_coder.model.top_k))
 }
 
-fn set_temperature(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_temperature(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: f64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("temperature(Current: {}):", open_coder.model.temperature))
@@ -80,7 +80,7 @@ fn set_temperature(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
     Ok(format!("Set temperature to {}", open_coder.model.temperature))
 }
 
-fn set_presence_penalty(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_presence_penalty(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: f64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("presence_penalty(Current: {}):", open_coder.model.presence_penalty))
@@ -92,7 +92,7 @@ fn set_presence_penalty(open_coder: &mut OpenCoder, arg: &str) -> Result<String>
     Ok(format!("Set presence_penalty to {}", open_coder.model.presence_penalty))
 }
 
-fn set_frequency_penalty(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_frequency_penalty(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: f64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("frequency_penalty(Current: {}):", open_coder.model.frequency_penalty))
@@ -104,7 +104,7 @@ fn set_frequency_penalty(open_coder: &mut OpenCoder, arg: &str) -> Result<String
     Ok(format!("Set frequency_penalty to {}", open_coder.model.frequency_penalty))
 }
 
-fn set_repeat_penalty(open_coder: &mut OpenCoder, arg: &str) -> Result<String> {
+fn set_repeat_penalty(open_coder: &mut OpenCoder, _arg: &str) -> Result<String> {
     println!();
     let input: f64 = Input::with_theme(&open_coder.theme)
         .with_prompt(format!("repeat_penalty(Current: {}):", open_coder.model.repeat_penalty))
@@ -1,6 +1,5 @@
 use crate::app::runner::OpenCoder;
 use crate::commands::command::Command;
-use crate::infrastructure::lm::client::Client;
 use std::{collections::HashMap, pin::Pin};
 
 use anyhow::{anyhow, Result};
@@ -23,6 +22,7 @@ impl CommandRegistry {
         self.handlers.insert(name, handler);
     }
 
+    #[allow(dead_code)]
     pub fn get_all_commands(&self) -> Vec<&Command> {
         self.commands.values().collect()
     }
@@ -1,11 +1,10 @@
 use crate::app::config::Config;
 
 use anyhow::{Context, Result};
-use futures::StreamExt;
-use reqwest::{Client as HttpClient, Error, Response};
-use reqwest_eventsource::{Event, EventSource};
+use reqwest::{Client as HttpClient, Response};
+use reqwest_eventsource::EventSource;
 use serde_json::{json, Value};
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, warn};
 
 #[derive(Clone)]
 pub struct ModelSettings {
@@ -67,6 +66,7 @@ impl Client {
         }
     }
 
+    #[allow(dead_code)]
     pub async fn chat_completions(&self, model: ModelSettings, prompt: String) -> Result<Response> {
         debug!("Posting chat completions...");
 
@@ -110,10 +110,11 @@ impl Client {
         &self,
         model: ModelSettings,
         messages: Vec<Value>,
+        tools: Vec<Value>,
     ) -> Result<EventSource> {
         debug!("Streaming chat completions...");
 
-        let request_body = json!({
+        let mut request_body = json!({
             "model": model.name,
             "messages": messages,
             "top_p": model.top_p,
@@ -125,6 +126,11 @@ impl Client {
             "stream": true,
         });
 
+        if !tools.is_empty() {
+            request_body["tools"] = json!(tools);
+            request_body["tool_choice"] = json!("auto");
+        }
+
         let request = self
             .http_client
             .post(format!("{}/chat/completions", self.api_url))
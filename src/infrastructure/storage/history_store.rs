@@ -1,3 +1,5 @@
+use crate::tools::call::ToolCall;
+
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
@@ -10,7 +12,14 @@ pub enum Role {
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct Message { role: String, content: String }
+pub struct Message {
+    role: String,
+    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_calls: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_call_id: Option<String>,
+}
 
 pub struct HistoryStore {
     history: Vec<Message>
@@ -19,12 +28,17 @@ pub struct HistoryStore {
 impl HistoryStore {
     pub fn new(system_prompt: &str) -> Result<Self> {
         Ok(Self {
-            history: vec![Message { role: "system".to_string(), content: system_prompt.to_string() }]
+            history: vec![Message {
+                role: "system".to_string(),
+                content: system_prompt.to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            }]
         })
     }
 
     pub fn history(&mut self) -> Vec<Value> {
-        self.history.iter().map(|m| json!({ "role": m.role, "content": m.content })).collect()
+        self.history.iter().map(|m| json!(m)).collect()
     }
 
     pub fn add_history(&mut self, role: Role, content: &str) -> Result<()> {
@@ -34,8 +48,35 @@ impl HistoryStore {
             Role::Assistant => "assistant",
         };
 
-        self.history.push(Message { role: role_str.to_string(), content: content.to_string() });
+        self.history.push(Message {
+            role: role_str.to_string(),
+            content: content.to_string(),
+            tool_calls: None,
+            tool_call_id: None,
+        });
+
+        Ok(())
+    }
+
+    pub fn add_tool_calls(&mut self, content: &str, tool_calls: &[ToolCall]) -> Result<()> {
+        self.history.push(Message {
+            role: "assistant".to_string(),
+            content: content.to_string(),
+            tool_calls: Some(tool_calls.iter().map(|call| call.to_json()).collect()),
+            tool_call_id: None,
+        });
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub fn add_tool_result(&mut self, tool_call_id: &str, content: &str) -> Result<()> {
+        self.history.push(Message {
+            role: "tool".to_string(),
+            content: content.to_string(),
+            tool_calls: None,
+            tool_call_id: Some(tool_call_id.to_string()),
+        });
+
+        Ok(())
+    }
+}
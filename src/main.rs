@@ -3,6 +3,7 @@ mod cli;
 mod commands;
 mod domain;
 mod infrastructure;
+mod tools;
 mod utils;
 
 use crate::app::config::Config;
@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+
+#[derive(Clone, Debug, Default)]
+pub struct ToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: String,
+}
+
+impl ToolCall {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "type": "function",
+            "function": {
+                "name": self.name,
+                "arguments": self.arguments,
+            }
+        })
+    }
+}
+
+/// ストリーミング中に届く`delta.tool_calls`の断片
+#[derive(Clone, Debug)]
+pub struct ToolCallDelta {
+    pub index: usize,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+impl ToolCallDelta {
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let index = value["index"].as_u64()? as usize;
+
+        Some(Self {
+            index,
+            id: value["id"].as_str().map(|s| s.to_string()),
+            name: value["function"]["name"].as_str().map(|s| s.to_string()),
+            arguments: value["function"]["arguments"].as_str().map(|s| s.to_string()),
+        })
+    }
+}
+
+/// indexごとに断片を連結してToolCallを組み立てる
+#[derive(Default)]
+pub struct ToolCallAccumulator {
+    calls: BTreeMap<usize, ToolCall>,
+}
+
+impl ToolCallAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, delta: ToolCallDelta) {
+        let call = self.calls.entry(delta.index).or_default();
+
+        if let Some(id) = delta.id {
+            call.id = id;
+        }
+        if let Some(name) = delta.name {
+            call.name.push_str(&name);
+        }
+        if let Some(arguments) = delta.arguments {
+            call.arguments.push_str(&arguments);
+        }
+    }
+
+    pub fn finish(self) -> Vec<ToolCall> {
+        self.calls
+            .into_values()
+            .map(|mut call| {
+                // idを返さないサーバーもあるので補完する
+                if call.id.is_empty() {
+                    call.id = format!("call_{}", uuid::Uuid::new_v4().simple());
+                }
+                call
+            })
+            .collect()
+    }
+}
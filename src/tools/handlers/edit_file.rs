@@ -0,0 +1,98 @@
+use crate::cli::output::OutputHandler;
+use crate::tools::tool::Tool;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+pub struct EditFileTool {
+    output: OutputHandler,
+}
+
+impl EditFileTool {
+    pub fn new() -> Result<Self> {
+        Ok(Self { output: OutputHandler::new()? })
+    }
+}
+
+#[async_trait]
+impl Tool for EditFileTool {
+    fn name(&self) -> &'static str {
+        "edit_file"
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace an exact string in a file. old_string must match the file contents exactly, \
+         including whitespace and indentation, and must occur exactly once. Include enough \
+         surrounding lines in old_string to make it unique. Read the file before editing it."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path of the file to edit, relative to the working directory or absolute."
+                },
+                "old_string": {
+                    "type": "string",
+                    "description": "The exact text to replace. Must occur exactly once in the file."
+                },
+                "new_string": {
+                    "type": "string",
+                    "description": "The text to replace old_string with."
+                }
+            },
+            "required": ["path", "old_string", "new_string"]
+        })
+    }
+
+    async fn execute(&mut self, args: Value) -> Result<String> {
+        let path = args["path"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing required argument: path"))?;
+        let old_string = args["old_string"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing required argument: old_string"))?;
+        let new_string = args["new_string"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing required argument: new_string"))?;
+
+        if old_string.is_empty() {
+            bail!("old_string must not be empty");
+        }
+        if old_string == new_string {
+            bail!("old_string and new_string are identical; nothing to change");
+        }
+
+        let path = PathBuf::from(path);
+        let original = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        match original.matches(old_string).count() {
+            0 => bail!(
+                "old_string was not found in {}. Read the file again and copy the text exactly",
+                path.display()
+            ),
+            1 => {}
+            n => bail!(
+                "old_string occurs {} times in {}. Add more surrounding context to make it unique",
+                n,
+                path.display()
+            ),
+        }
+
+        let updated = original.replacen(old_string, new_string, 1);
+
+        self.output.print_diff(&path.display().to_string(), &original, &updated)?;
+
+        tokio::fs::write(&path, &updated)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(format!("Edited {}", path.display()))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod edit_file;
+pub mod read_file;
+pub mod run_shell;
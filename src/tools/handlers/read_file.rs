@@ -0,0 +1,123 @@
+use crate::tools::tool::Tool;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+const DEFAULT_LIMIT: usize = 2000;
+const MAX_LINE_CHARS: usize = 2000;
+const MAX_OUTPUT_BYTES: usize = 100 * 1024;
+// 先頭からこのバイト数を見てバイナリかどうかを判定する
+const BINARY_CHECK_BYTES: usize = 8 * 1024;
+
+pub struct ReadFileTool {}
+
+impl ReadFileTool {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Tool for ReadFileTool {
+    fn name(&self) -> &'static str {
+        "read_file"
+    }
+
+    fn description(&self) -> &'static str {
+        "Read a text file and return its contents with 1-based line numbers. \
+         Use offset and limit to read a specific range of lines from large files."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path of the file to read, relative to the working directory or absolute."
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "1-based line number to start reading from. Defaults to 1."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": format!("Maximum number of lines to read. Defaults to {}.", DEFAULT_LIMIT)
+                }
+            },
+            "required": ["path"]
+        })
+    }
+
+    async fn execute(&mut self, args: Value) -> Result<String> {
+        let path = args["path"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing required argument: path"))?;
+        let offset = args["offset"].as_u64().unwrap_or(1).max(1) as usize;
+        let limit = args["limit"].as_u64().map(|l| l as usize).unwrap_or(DEFAULT_LIMIT);
+
+        let path = PathBuf::from(path);
+        let bytes = tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        if is_binary(&bytes) {
+            bail!("{} looks like a binary file and cannot be read as text", path.display());
+        }
+
+        let text = String::from_utf8_lossy(&bytes);
+        Ok(format_lines(&text, offset, limit))
+    }
+}
+
+fn is_binary(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(BINARY_CHECK_BYTES)];
+    if head.contains(&0) {
+        return true;
+    }
+
+    // 途中で切れたマルチバイト文字は無視する
+    match std::str::from_utf8(head) {
+        Ok(_) => false,
+        Err(err) => err.error_len().is_some(),
+    }
+}
+
+fn format_lines(text: &str, offset: usize, limit: usize) -> String {
+    let total_lines = text.lines().count();
+    if total_lines == 0 {
+        return "(empty file)".to_string();
+    }
+    if offset > total_lines {
+        return format!("(offset {} is past the end of the file, which has {} lines)", offset, total_lines);
+    }
+
+    let mut output = String::new();
+    let mut last_line = offset - 1;
+
+    for (index, line) in text.lines().enumerate().skip(offset - 1).take(limit) {
+        let line = match line.char_indices().nth(MAX_LINE_CHARS) {
+            Some((cut, _)) => format!("{}... [line truncated]", &line[..cut]),
+            None => line.to_string(),
+        };
+
+        let numbered = format!("{:>6}\t{}\n", index + 1, line);
+        if output.len() + numbered.len() > MAX_OUTPUT_BYTES {
+            break;
+        }
+
+        output.push_str(&numbered);
+        last_line = index + 1;
+    }
+
+    if last_line < total_lines {
+        output.push_str(&format!(
+            "... [truncated: showing lines {}-{} of {}. Use offset and limit to read more]\n",
+            offset, last_line, total_lines
+        ));
+    }
+
+    output
+}
@@ -0,0 +1,216 @@
+use crate::cli::output::OutputHandler;
+use crate::tools::tool::Tool;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+const MAX_TIMEOUT_SECS: u64 = 600;
+const MAX_OUTPUT_BYTES: usize = 30 * 1024;
+
+pub struct RunShellTool {
+    output: OutputHandler,
+    // セッション中はcdの結果を引き継ぐ
+    cwd: PathBuf,
+}
+
+impl RunShellTool {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            output: OutputHandler::new()?,
+            cwd: std::env::current_dir().context("Failed to get current directory")?,
+        })
+    }
+}
+
+#[async_trait]
+impl Tool for RunShellTool {
+    fn name(&self) -> &'static str {
+        "run_shell"
+    }
+
+    fn description(&self) -> &'static str {
+        "Run a shell command with `sh -c` and return its exit code and combined stdout/stderr. \
+         The working directory persists between calls, so `cd` affects later commands. \
+         Long outputs are truncated in the middle."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "command": {
+                    "type": "string",
+                    "description": "The shell command to run."
+                },
+                "timeout_secs": {
+                    "type": "integer",
+                    "description": format!(
+                        "Seconds before the command is killed. Defaults to {}, at most {}.",
+                        DEFAULT_TIMEOUT_SECS, MAX_TIMEOUT_SECS
+                    )
+                }
+            },
+            "required": ["command"]
+        })
+    }
+
+    async fn execute(&mut self, args: Value) -> Result<String> {
+        let command = args["command"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing required argument: command"))?;
+        let timeout_secs = args["timeout_secs"]
+            .as_u64()
+            .unwrap_or(DEFAULT_TIMEOUT_SECS)
+            .clamp(1, MAX_TIMEOUT_SECS);
+
+        // コマンド終了後のカレントディレクトリを一時ファイル経由で受け取る
+        let cwd_file = std::env::temp_dir().join(format!("opencoder-cwd-{}", uuid::Uuid::new_v4().simple()));
+        let script = format!(
+            "{}\n__opencoder_status=$?\npwd > '{}'\nexit $__opencoder_status",
+            command,
+            cwd_file.display()
+        );
+
+        debug!("Running shell command in {}: {}", self.cwd.display(), command);
+
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(&script)
+            .current_dir(&self.cwd)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .process_group(0)
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("Failed to spawn command: {}", command))?;
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        if let Some(stdout) = child.stdout.take() {
+            tokio::spawn(forward_lines(stdout, false, tx.clone()));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(forward_lines(stderr, true, tx.clone()));
+        }
+        drop(tx);
+
+        let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+        let mut output = String::new();
+        let mut timed_out = false;
+
+        // 出力をリアルタイムに表示しながら溜める
+        loop {
+            tokio::select! {
+                line = rx.recv() => match line {
+                    Some((is_stderr, line)) => {
+                        self.output.print_shell_line(&line, is_stderr)?;
+                        output.push_str(&line);
+                        output.push('\n');
+                    }
+                    None => break,
+                },
+                _ = tokio::time::sleep_until(deadline) => {
+                    timed_out = true;
+                    break;
+                }
+            }
+        }
+
+        let status = if timed_out {
+            None
+        } else {
+            match tokio::time::timeout_at(deadline, child.wait()).await {
+                Ok(status) => Some(status.context("Failed to wait for command")?),
+                Err(_) => {
+                    timed_out = true;
+                    None
+                }
+            }
+        };
+
+        if timed_out {
+            warn!("Command timed out after {}s: {}", timeout_secs, command);
+            kill_process_group(&mut child).await;
+        }
+
+        if let Ok(cwd) = tokio::fs::read_to_string(&cwd_file).await {
+            let cwd = PathBuf::from(cwd.trim());
+            if cwd.is_dir() {
+                self.cwd = cwd;
+            }
+        }
+        let _ = tokio::fs::remove_file(&cwd_file).await;
+
+        let exit = match status {
+            Some(status) => match status.code() {
+                Some(code) => format!("Exit code: {}", code),
+                None => "Exit code: none (terminated by signal)".to_string(),
+            },
+            None => format!("Timed out after {} seconds; the command was killed", timeout_secs),
+        };
+
+        Ok(format!(
+            "{}\nWorking directory: {}\n\n{}",
+            exit,
+            self.cwd.display(),
+            truncate_middle(&output, MAX_OUTPUT_BYTES)
+        ))
+    }
+}
+
+async fn forward_lines<R>(reader: R, is_stderr: bool, tx: mpsc::UnboundedSender<(bool, String)>)
+where
+    R: AsyncRead + Unpin,
+{
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if tx.send((is_stderr, line)).is_err() {
+            break;
+        }
+    }
+}
+
+async fn kill_process_group(child: &mut tokio::process::Child) {
+    // sh -cから起動された子プロセスもまとめて終了させる
+    if let Some(pid) = child.id() {
+        let _ = Command::new("kill")
+            .arg("-9")
+            .arg(format!("-{}", pid))
+            .status()
+            .await;
+    }
+    let _ = child.kill().await;
+}
+
+fn truncate_middle(output: &str, max_bytes: usize) -> String {
+    if output.len() <= max_bytes {
+        return output.to_string();
+    }
+
+    let half = max_bytes / 2;
+    let mut head_end = half;
+    while !output.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+    let mut tail_start = output.len() - half;
+    while !output.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+
+    format!(
+        "{}\n... [{} bytes truncated] ...\n{}",
+        &output[..head_end],
+        tail_start - head_end,
+        &output[tail_start..]
+    )
+}
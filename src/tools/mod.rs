@@ -0,0 +1,4 @@
+pub mod call;
+pub mod handlers;
+pub mod registry;
+pub mod tool;
@@ -0,0 +1,43 @@
+use crate::tools::{call::ToolCall, tool::Tool};
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, anyhow};
+use serde_json::Value;
+
+pub struct ToolRegistry {
+    tools: HashMap<String, Box<dyn Tool>>,
+}
+
+impl ToolRegistry {
+    pub fn new(tools: Vec<Box<dyn Tool>>) -> Self {
+        let tools = tools
+            .into_iter()
+            .map(|tool| (tool.name().to_string(), tool))
+            .collect();
+
+        Self { tools }
+    }
+
+    pub fn definitions(&self) -> Vec<Value> {
+        let mut names: Vec<&String> = self.tools.keys().collect();
+        names.sort();
+
+        names.into_iter().map(|name| self.tools[name].definition()).collect()
+    }
+
+    pub async fn execute(&mut self, call: &ToolCall) -> Result<String> {
+        let tool = self
+            .tools
+            .get_mut(&call.name)
+            .ok_or_else(|| anyhow!("Unknown tool: {}", call.name))?;
+
+        let args: Value = if call.arguments.trim().is_empty() {
+            Value::Object(Default::default())
+        } else {
+            serde_json::from_str(&call.arguments)
+                .with_context(|| format!("Invalid arguments for {}: {}", call.name, call.arguments))?
+        };
+
+        tool.execute(args).await
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+#[async_trait]
+pub trait Tool: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    /// 引数のJSON Schema
+    fn parameters(&self) -> Value;
+
+    async fn execute(&mut self, args: Value) -> Result<String>;
+
+    /// OpenAI API互換の`tools`に渡す定義
+    fn definition(&self) -> Value {
+        json!({
+            "type": "function",
+            "function": {
+                "name": self.name(),
+                "description": self.description(),
+                "parameters": self.parameters(),
+            }
+        })
+    }
+}